@@ -0,0 +1,96 @@
+//! `#[derive(FeatherJson)]` - generates `Encode`/`Decode` impls for a
+//! struct by walking its named fields. Each field is written/read under
+//! its own name via `feather_json::codec::{Encode, Decode}`, so anything
+//! that already implements those (the primitives, `Option<T>`, `Vec<T>`,
+//! and other `#[derive(FeatherJson)]` structs) can be nested freely.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, PathArguments, Type, parse_macro_input};
+
+/// Whether `ty` is written as `Option<...>`. Used to tell an absent field
+/// (should decode to `None`) apart from a genuinely required one (should
+/// error via `JsonError::MissingField`).
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path.path.segments.last()
+            .is_some_and(|segment| {
+                segment.ident == "Option"
+                    && matches!(segment.arguments, PathArguments::AngleBracketed(_))
+            }),
+        _ => false,
+    }
+}
+
+#[proc_macro_derive(FeatherJson)]
+pub fn derive_feather_json(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => panic!("FeatherJson only supports structs with named fields"),
+        },
+        _ => panic!("FeatherJson only supports structs"),
+    };
+
+    let field_idents: Vec<_> = fields.iter().map(|f| f.ident.as_ref().unwrap()).collect();
+    let field_names: Vec<_> = field_idents.iter().map(|ident| ident.to_string()).collect();
+
+    let encode_calls = field_idents.iter().zip(&field_names).map(|(ident, name)| {
+        quote! { let builder = self.#ident.encode_into(#name, builder); }
+    });
+
+    let decode_locals = field_idents.iter().map(|ident| {
+        quote! { let mut #ident = None; }
+    });
+
+    let decode_matches = field_idents.iter().zip(&field_names).map(|(ident, name)| {
+        quote! {
+            #name => #ident = Some(
+                ::feather_json::codec::Decode::decode(field_value)?
+            ),
+        }
+    });
+
+    let decode_fields = fields.iter().zip(&field_idents).zip(&field_names)
+        .map(|((field, ident), name)| {
+            if is_option(&field.ty) {
+                quote! { #ident: #ident.unwrap_or(None), }
+            } else {
+                quote! {
+                    #ident: #ident.ok_or_else(|| ::feather_json::error::JsonError::MissingField(#name.to_string()))?,
+                }
+            }
+        });
+
+    let expanded = quote! {
+        impl ::feather_json::codec::Encode for #name {
+            fn encode_fields(&self, builder: ::feather_json::json::JsonBuilder) -> ::feather_json::json::JsonBuilder {
+                #(#encode_calls)*
+                builder
+            }
+        }
+
+        impl ::feather_json::codec::Decode for #name {
+            fn decode(value: ::feather_json::json::JsonValue) -> ::feather_json::error::JsonResult<Self> {
+                let mut cursor: ::feather_json::codec::DecodeCursor = value.try_into()?;
+                #(#decode_locals)*
+
+                while let Some((field_key, field_value)) = cursor.next_field() {
+                    match field_key.as_str() {
+                        #(#decode_matches)*
+                        _ => {}
+                    }
+                }
+
+                Ok(#name {
+                    #(#decode_fields)*
+                })
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
@@ -0,0 +1,167 @@
+use crate::{
+    error::{JsonError, JsonResult},
+    json::{Json, JsonBuilder, JsonValue},
+};
+
+/// Converts a Rust value into JSON by driving a `JsonBuilder`, one field
+/// at a time. Implemented here for the primitives already covered by
+/// `JsonValue`; `#[derive(FeatherJson)]` generates `encode_fields` for
+/// structs so `Json::encode(&value)` and nested fields both just work.
+pub trait Encode {
+    /// Writes `self` as the value of `key` onto `builder`. The default
+    /// assumes `Self` is itself an object and opens one via `encode_fields`.
+    fn encode_into(&self, key: &str, builder: JsonBuilder) -> JsonBuilder {
+        self.encode_fields(builder.object(key)).object_end()
+    }
+
+    /// Writes this value's own fields onto an already-open `builder`.
+    /// Only meaningful for struct types - derive generates this, and the
+    /// default is a no-op so leaf types only have to override
+    /// `encode_into`/`to_value`.
+    fn encode_fields(&self, builder: JsonBuilder) -> JsonBuilder {
+        builder
+    }
+
+    /// Renders `self` as a standalone `JsonValue`, for contexts with no
+    /// open builder to write into (array elements, for instance).
+    fn to_value(&self) -> JsonValue {
+        self.encode_fields(JsonBuilder::new())
+            .build()
+            .to_value()
+            .unwrap_or(JsonValue::Null)
+    }
+}
+
+macro_rules! impl_encode_scalar {
+    ($ty:ty, $variant:ident) => {
+        impl Encode for $ty {
+            fn encode_into(&self, key: &str, builder: JsonBuilder) -> JsonBuilder {
+                builder.value(key, self.clone())
+            }
+
+            fn to_value(&self) -> JsonValue {
+                JsonValue::$variant(self.clone())
+            }
+        }
+    };
+}
+
+impl_encode_scalar!(i64, Int);
+impl_encode_scalar!(f64, Float);
+impl_encode_scalar!(bool, Bool);
+
+impl Encode for String {
+    fn encode_into(&self, key: &str, builder: JsonBuilder) -> JsonBuilder {
+        builder.value(key, self.as_str())
+    }
+
+    fn to_value(&self) -> JsonValue {
+        JsonValue::String(self.clone())
+    }
+}
+
+impl<T: Encode> Encode for Option<T> {
+    fn encode_into(&self, key: &str, builder: JsonBuilder) -> JsonBuilder {
+        match self {
+            Some(value) => value.encode_into(key, builder),
+            None => builder.value(key, JsonValue::Null),
+        }
+    }
+
+    fn to_value(&self) -> JsonValue {
+        match self {
+            Some(value) => value.to_value(),
+            None => JsonValue::Null,
+        }
+    }
+}
+
+impl<T: Encode> Encode for Vec<T> {
+    fn encode_into(&self, key: &str, builder: JsonBuilder) -> JsonBuilder {
+        builder.array(key, self.iter().map(Encode::to_value).collect())
+    }
+
+    fn to_value(&self) -> JsonValue {
+        JsonValue::Array(self.iter().map(Encode::to_value).collect())
+    }
+}
+
+/// Reconstructs a Rust value from a materialized `JsonValue`. Implemented
+/// here for the primitives already covered by `JsonValue`; struct types
+/// get an impl from `#[derive(FeatherJson)]` that pulls its fields off a
+/// `DecodeCursor` in the order they were written.
+pub trait Decode: Sized {
+    fn decode(value: JsonValue) -> JsonResult<Self>;
+}
+
+macro_rules! impl_decode_scalar {
+    ($ty:ty) => {
+        impl Decode for $ty {
+            fn decode(value: JsonValue) -> JsonResult<Self> {
+                value.try_into()
+            }
+        }
+    };
+}
+
+impl_decode_scalar!(i64);
+impl_decode_scalar!(f64);
+impl_decode_scalar!(bool);
+impl_decode_scalar!(String);
+
+impl<T: Decode> Decode for Option<T> {
+    fn decode(value: JsonValue) -> JsonResult<Self> {
+        match value {
+            JsonValue::Null => Ok(None),
+            other => T::decode(other).map(Some),
+        }
+    }
+}
+
+impl<T: Decode> Decode for Vec<T> {
+    fn decode(value: JsonValue) -> JsonResult<Self> {
+        let values: Vec<JsonValue> = value.try_into()?;
+        values.into_iter().map(T::decode).collect()
+    }
+}
+
+/// Yields a struct's `(key, value)` pairs one at a time, in the order they
+/// were written. `#[derive(FeatherJson)]` pulls from this to build each
+/// field, reporting `JsonError::MissingField` for whatever it never sees.
+pub struct DecodeCursor {
+    entries: std::vec::IntoIter<(String, JsonValue)>,
+}
+
+impl DecodeCursor {
+    fn new(entries: Vec<(String, JsonValue)>) -> Self {
+        DecodeCursor { entries: entries.into_iter() }
+    }
+
+    pub fn next_field(&mut self) -> Option<(String, JsonValue)> {
+        self.entries.next()
+    }
+}
+
+impl TryInto<DecodeCursor> for JsonValue {
+    type Error = JsonError;
+
+    fn try_into(self) -> JsonResult<DecodeCursor> {
+        match self {
+            JsonValue::Object(entries) => Ok(DecodeCursor::new(entries)),
+            _ => Err(JsonError::JsonValueIsNotObject),
+        }
+    }
+}
+
+impl Json {
+    /// Builds a fresh `Json` document out of `value` by driving a
+    /// `JsonBuilder` through its fields.
+    pub fn encode<T: Encode>(value: &T) -> Json {
+        value.encode_fields(JsonBuilder::new()).build()
+    }
+
+    /// Reconstructs a `T` from this document's token stream.
+    pub fn decode<T: Decode>(&self) -> JsonResult<T> {
+        T::decode(self.to_value()?)
+    }
+}
@@ -0,0 +1,123 @@
+use crate::json::JsonValue;
+
+/// Escapes `value` per the standard JSON two-character escapes (`\" \\ \b
+/// \f \n \r \t`), falls back to `\uXXXX` for any other control character,
+/// and wraps the result in quotes - the inverse of `json::unescape`.
+pub(crate) fn escape_into(value: &str, buf: &mut String) {
+    buf.push('"');
+
+    for ch in value.chars() {
+        match ch {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\u{8}' => buf.push_str("\\b"),
+            '\u{c}' => buf.push_str("\\f"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            other if (other as u32) < 0x20 => {
+                buf.push_str(&format!("\\u{:04x}", other as u32));
+            }
+            _ => buf.push(ch),
+        }
+    }
+
+    buf.push('"');
+}
+
+/// Serializes a `JsonValue` back into compact JSON text.
+///
+/// Unlike `Json::to_string`, which concatenates already-lexed token text,
+/// this walks a standalone `JsonValue` tree (for example one returned by
+/// `Json::get`) and re-emits it from scratch.
+///
+/// # Examples
+/// ```
+/// assert_eq!(to_string(&JsonValue::Int(42)), "42");
+/// assert_eq!(to_string(&JsonValue::Array(vec![JsonValue::Bool(true)])), "[true]");
+/// ```
+pub fn to_string(value: &JsonValue) -> String {
+    let mut buf = String::new();
+    write_value(value, &mut buf);
+    buf
+}
+
+fn write_value(value: &JsonValue, buf: &mut String) {
+    match value {
+        JsonValue::Null => buf.push_str("null"),
+        JsonValue::Int(v) => buf.push_str(&v.to_string()),
+        JsonValue::Float(v) => buf.push_str(&v.to_string()),
+        JsonValue::Bool(v) => buf.push_str(&v.to_string()),
+        JsonValue::String(v) => escape_into(v, buf),
+        JsonValue::Array(values) => {
+            buf.push('[');
+
+            for (i, value) in values.iter().enumerate() {
+                if i != 0 { buf.push(','); }
+                write_value(value, buf);
+            }
+
+            buf.push(']');
+        }
+        JsonValue::Object(entries) => {
+            buf.push('{');
+
+            for (i, (key, value)) in entries.iter().enumerate() {
+                if i != 0 { buf.push(','); }
+                escape_into(key, buf);
+                buf.push(':');
+                write_value(value, buf);
+            }
+
+            buf.push('}');
+        }
+    }
+}
+
+fn write_value_pretty(value: &JsonValue, buf: &mut String, indent: usize, level: usize) {
+    match value {
+        JsonValue::Array(values) if !values.is_empty() => {
+            buf.push_str("[\n");
+
+            for (i, value) in values.iter().enumerate() {
+                if i != 0 { buf.push_str(",\n"); }
+                buf.extend(std::iter::repeat(' ').take(indent * (level + 1)));
+                write_value_pretty(value, buf, indent, level + 1);
+            }
+
+            buf.push('\n');
+            buf.extend(std::iter::repeat(' ').take(indent * level));
+            buf.push(']');
+        }
+        JsonValue::Object(entries) if !entries.is_empty() => {
+            buf.push_str("{\n");
+
+            for (i, (key, value)) in entries.iter().enumerate() {
+                if i != 0 { buf.push_str(",\n"); }
+                buf.extend(std::iter::repeat(' ').take(indent * (level + 1)));
+                escape_into(key, buf);
+                buf.push_str(": ");
+                write_value_pretty(value, buf, indent, level + 1);
+            }
+
+            buf.push('\n');
+            buf.extend(std::iter::repeat(' ').take(indent * level));
+            buf.push('}');
+        }
+        _ => write_value(value, buf),
+    }
+}
+
+/// Serializes a `JsonValue` into human-readable JSON text, indenting nested
+/// arrays by `indent` spaces per level.
+///
+/// # Examples
+/// ```
+/// let value = JsonValue::Array(vec![JsonValue::Int(1), JsonValue::Int(2)]);
+/// assert_eq!(to_string_pretty(&value, 2), "[\n  1,\n  2\n]");
+/// ```
+pub fn to_string_pretty(value: &JsonValue, indent: usize) -> String {
+    let mut buf = String::new();
+    write_value_pretty(value, &mut buf, indent, 0);
+    buf
+}
@@ -5,11 +5,11 @@ pub enum JsonError {
     #[error("An empty path is an invalid path.")]
     NoPathProvided,
 
-    #[error("Invalid path to value.")]
-    InvalidPath,
+    #[error("Invalid path to value at line {0}, column {1}.")]
+    InvalidPath(usize, usize),
 
-    #[error("Invalid Json")]
-    InvalidJson,
+    #[error("Invalid Json at line {0}, column {1}.")]
+    InvalidJson(usize, usize),
 
     #[error("")]
     InsertCantInsertIntoValue,
@@ -28,6 +28,18 @@ pub enum JsonError {
 
     #[error("Json value is not a String.")]
     JsonValueIsNotString,
+
+    #[error("Json value is not an array.")]
+    JsonValueIsNotArray,
+
+    #[error("Json value is not an object.")]
+    JsonValueIsNotObject,
+
+    #[error("Missing required field \"{0}\".")]
+    MissingField(String),
+
+    #[error("Invalid escape sequence in string literal.")]
+    InvalidEscape,
 }
 
 impl PartialEq for JsonError {
@@ -36,10 +48,14 @@ impl PartialEq for JsonError {
 
         match (self, other) {
             (NoPathProvided, NoPathProvided) => true,
-            (InvalidPath, InvalidPath) => true,
-            (InvalidJson, InvalidJson) => true,
+            (InvalidPath(_, _), InvalidPath(_, _)) => true,
+            (InvalidJson(_, _), InvalidJson(_, _)) => true,
             (InsertCantInsertIntoValue, InsertCantInsertIntoValue) => true,
             (StdInputOutputError(_), StdInputOutputError(_)) => true,
+            (JsonValueIsNotArray, JsonValueIsNotArray) => true,
+            (JsonValueIsNotObject, JsonValueIsNotObject) => true,
+            (MissingField(_), MissingField(_)) => true,
+            (InvalidEscape, InvalidEscape) => true,
 
             _ => false,
         }
@@ -0,0 +1,219 @@
+use crate::{error::{JsonError, JsonResult}, json::{self, JsonValue}};
+use std::io::{self, Bytes, Read};
+
+/// A single step of a `JsonEvents` pull, mirroring the event stream a
+/// caller would see if they drove the lexer by hand - in the style of the
+/// classic Rust JSON `Parser`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonEvent {
+    ObjectStart,
+    ObjectEnd,
+    ArrayStart,
+    ArrayEnd,
+    Key(String),
+    Value(JsonValue),
+}
+
+enum Container {
+    Object,
+    Array,
+}
+
+/// A pull-style reader that yields `JsonEvent`s straight off an
+/// `io::Read`, one byte at a time, without ever lexing the whole input
+/// into a `Vec<Token>`. Useful for scanning multi-megabyte documents in
+/// bounded memory.
+pub struct JsonEvents<R: Read> {
+    bytes: Bytes<io::BufReader<R>>,
+    stack: Vec<Container>,
+    lexeme: Vec<u8>,
+    in_string: bool,
+    /// A structural byte whose preceding lexeme has already been flushed
+    /// as an event; acted on (without reading another byte) on the next
+    /// call to `next`.
+    pending: Option<u8>,
+    errored: bool,
+    line: usize,
+    col: usize,
+}
+
+impl<R: Read> JsonEvents<R> {
+    pub fn from_reader(reader: R) -> Self {
+        JsonEvents {
+            bytes: io::BufReader::new(reader).bytes(),
+            stack: vec![],
+            lexeme: vec![],
+            in_string: false,
+            pending: None,
+            errored: false,
+            line: 1,
+            col: 0,
+        }
+    }
+
+    fn read_byte(&mut self) -> Option<JsonResult<u8>> {
+        self.bytes.next().map(|r| r.map_err(JsonError::StdInputOutputError)).inspect(|result| {
+            if let Ok(byte) = result {
+                self.col += 1;
+                if *byte == b'\n' { self.line += 1; self.col = 0; }
+            }
+        })
+    }
+
+    /// Copies the character right after a `\` inside a string into
+    /// `self.lexeme` raw, escape sequence untouched - same as the
+    /// in-memory lexer, decoding is left to `json::unescape` once the
+    /// whole lexeme has been flushed.
+    fn push_escape(&mut self) -> JsonResult<()> {
+        self.lexeme.push(b'\\');
+
+        match self.read_byte() {
+            Some(Ok(byte)) => self.lexeme.push(byte),
+            Some(Err(e)) => return Err(e),
+            None => return Err(JsonError::InvalidJson(self.line, self.col)),
+        }
+
+        Ok(())
+    }
+
+    fn flush_value(&mut self) -> JsonResult<JsonEvent> {
+        let text = String::from_utf8_lossy(&self.lexeme).into_owned();
+        self.lexeme.clear();
+        Ok(JsonEvent::Value(JsonValue::parse(&text)?))
+    }
+
+    /// Flushes the buffered lexeme as a `Key`, unescaping its surrounding
+    /// quotes (the buffer still holds them, same as a lexed `Key` token).
+    fn flush_key(&mut self) -> JsonResult<JsonEvent> {
+        let text = String::from_utf8_lossy(&self.lexeme).into_owned();
+        self.lexeme.clear();
+        Ok(JsonEvent::Key(json::unescape(&text)?))
+    }
+
+    fn fail(&mut self) -> JsonResult<JsonEvent> {
+        self.errored = true;
+        Err(JsonError::InvalidJson(self.line, self.col))
+    }
+}
+
+impl<R: Read> Iterator for JsonEvents<R> {
+    type Item = JsonResult<JsonEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.errored { return None; }
+
+        loop {
+            let byte = match self.pending.take() {
+                Some(byte) => byte,
+                None => match self.read_byte() {
+                    Some(Ok(byte)) => byte,
+                    Some(Err(e)) => { self.errored = true; return Some(Err(e)); }
+                    None => {
+                        return if !self.stack.is_empty() || self.in_string {
+                            Some(self.fail())
+                        } else if self.lexeme.is_empty() {
+                            None
+                        } else {
+                            Some(self.flush_value().inspect_err(|_| self.errored = true))
+                        };
+                    }
+                },
+            };
+
+            if self.in_string {
+                match byte {
+                    b'"' => { self.lexeme.push(b'"'); self.in_string = false; }
+                    b'\\' => if let Err(e) = self.push_escape() {
+                        self.errored = true;
+                        return Some(Err(e));
+                    },
+                    other => self.lexeme.push(other),
+                }
+                continue;
+            }
+
+            match byte {
+                b' ' | b'\t' | b'\r' | b'\n' => continue,
+                b'"' => { self.lexeme.push(b'"'); self.in_string = true; }
+                b'{' => {
+                    self.stack.push(Container::Object);
+                    return Some(Ok(JsonEvent::ObjectStart));
+                }
+                b'[' => {
+                    self.stack.push(Container::Array);
+                    return Some(Ok(JsonEvent::ArrayStart));
+                }
+                b'}' => {
+                    if !self.lexeme.is_empty() {
+                        self.pending = Some(byte);
+                        return Some(self.flush_value().inspect_err(|_| self.errored = true));
+                    }
+                    return Some(match self.stack.pop() {
+                        Some(Container::Object) => Ok(JsonEvent::ObjectEnd),
+                        _ => self.fail(),
+                    });
+                }
+                b']' => {
+                    if !self.lexeme.is_empty() {
+                        self.pending = Some(byte);
+                        return Some(self.flush_value().inspect_err(|_| self.errored = true));
+                    }
+                    return Some(match self.stack.pop() {
+                        Some(Container::Array) => Ok(JsonEvent::ArrayEnd),
+                        _ => self.fail(),
+                    });
+                }
+                b':' => {
+                    if self.lexeme.is_empty() { return Some(self.fail()); }
+                    return Some(self.flush_key().inspect_err(|_| self.errored = true));
+                }
+                b',' => {
+                    if !self.lexeme.is_empty() {
+                        return Some(self.flush_value().inspect_err(|_| self.errored = true));
+                    }
+                    // Otherwise the comma follows a nested `}`/`]` whose
+                    // End event was already emitted - nothing to flush.
+                }
+                other => self.lexeme.push(other),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn yields_a_bare_top_level_int() {
+        let mut events = JsonEvents::from_reader("42".as_bytes());
+        assert_eq!(events.next(), Some(Ok(JsonEvent::Value(JsonValue::Int(42)))));
+        assert_eq!(events.next(), None);
+    }
+
+    #[test]
+    fn yields_a_bare_top_level_string() {
+        let mut events = JsonEvents::from_reader(r#""hello""#.as_bytes());
+        assert_eq!(events.next(), Some(Ok(JsonEvent::Value(JsonValue::String("hello".to_string())))));
+        assert_eq!(events.next(), None);
+    }
+
+    #[test]
+    fn errors_on_an_unterminated_top_level_string() {
+        let mut events = JsonEvents::from_reader(r#""hello"#.as_bytes());
+        assert_eq!(events.next(), Some(Err(JsonError::InvalidJson(1, 6))));
+    }
+
+    #[test]
+    fn walks_a_nested_object() {
+        let mut events = JsonEvents::from_reader(r#"{"a":[1,2]}"#.as_bytes());
+        assert_eq!(events.next(), Some(Ok(JsonEvent::ObjectStart)));
+        assert_eq!(events.next(), Some(Ok(JsonEvent::Key("a".to_string()))));
+        assert_eq!(events.next(), Some(Ok(JsonEvent::ArrayStart)));
+        assert_eq!(events.next(), Some(Ok(JsonEvent::Value(JsonValue::Int(1)))));
+        assert_eq!(events.next(), Some(Ok(JsonEvent::Value(JsonValue::Int(2)))));
+        assert_eq!(events.next(), Some(Ok(JsonEvent::ArrayEnd)));
+        assert_eq!(events.next(), Some(Ok(JsonEvent::ObjectEnd)));
+        assert_eq!(events.next(), None);
+    }
+}
@@ -1,14 +1,87 @@
 use crate::{error::{JsonError, JsonResult}, lexer::{lex, lex_from_file}, token::{Token, TokenType}};
-use std::{fs, io};
+use std::{fs, io, str::Chars};
+
+/// Reads the four hex digits of a `\uXXXX` escape (the cursor must already
+/// be positioned right after the `u`) into a UTF-16 code unit.
+fn read_hex4(chars: &mut Chars) -> JsonResult<u32> {
+    let hex: String = chars.take(4).collect();
+
+    if hex.len() != 4 { return Err(JsonError::InvalidEscape); }
+    u32::from_str_radix(&hex, 16).map_err(|_| JsonError::InvalidEscape)
+}
+
+/// Decodes a raw `"..."` token lexeme - quotes included - into its clean
+/// text, resolving `\" \\ \/ \b \f \n \r \t` and `\uXXXX` (merging UTF-16
+/// surrogate pairs) into their real characters.
+pub(crate) fn unescape(lexeme: &str) -> JsonResult<String> {
+    let mut chars = lexeme[1..lexeme.len() - 1].chars();
+    let mut result = String::with_capacity(lexeme.len());
+
+    while let Some(ch) = chars.next() {
+        if ch != '\\' { result.push(ch); continue; }
+
+        match chars.next() {
+            Some('"') => result.push('"'),
+            Some('\\') => result.push('\\'),
+            Some('/') => result.push('/'),
+            Some('b') => result.push('\u{8}'),
+            Some('f') => result.push('\u{c}'),
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            Some('t') => result.push('\t'),
+            Some('u') => {
+                let high = read_hex4(&mut chars)?;
+
+                let decoded = if (0xD800..=0xDBFF).contains(&high) {
+                    match (chars.next(), chars.next()) {
+                        (Some('\\'), Some('u')) => {
+                            let low = read_hex4(&mut chars)?;
+
+                            if (0xDC00..=0xDFFF).contains(&low) {
+                                char::from_u32(((high - 0xD800) << 10) + (low - 0xDC00) + 0x10000)
+                            } else {
+                                None
+                            }
+                        }
+                        _ => None,
+                    }
+                } else {
+                    char::from_u32(high)
+                };
+
+                result.push(decoded.ok_or(JsonError::InvalidEscape)?);
+            }
+            _ => return Err(JsonError::InvalidEscape),
+        }
+    }
+
+    Ok(result)
+}
+
+/// Unescapes a key lexeme for JSONPath matching, falling back to a plain
+/// quote-strip on malformed input rather than failing the whole query -
+/// `query` treats an unmatched path as an empty result, not an error.
+fn unescape_lossy(lexeme: &str) -> String {
+    unescape(lexeme).unwrap_or_else(|_| lexeme[1..lexeme.len() - 1].to_string())
+}
+
+/// Wraps `key` in quotes, escaping any characters that need it.
+fn quote_key(key: &str) -> String {
+    let mut buf = String::with_capacity(key.len() + 2);
+    crate::encoder::escape_into(key, &mut buf);
+    buf
+}
 
 #[derive(Debug, Clone, PartialEq)]
 #[repr(u8)]
 pub enum JsonValue {
-    Int(i32),
-    Float(f32),
+    Null,
+    Int(i64),
+    Float(f64),
     Bool(bool),
     String(String),
     Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
 }
 
 impl JsonValue {
@@ -18,27 +91,38 @@ impl JsonValue {
     /// 
     /// # Notes
     /// - `"true"` and `"false"` are parsed as `JsonValue::Bool`
-    /// - Numeric strings are parsed as `JsonValue::Int` or `JsonValue::Float`
+    /// - `"null"` is parsed as `JsonValue::Null`
+    /// - Numeric strings are parsed as `JsonValue::Int` (`i64`) or
+    ///   `JsonValue::Float` (`f64`)
     /// - All other input is returned as `JsonValue::String`
     ///
+    /// # Notes
+    /// - A lexeme still wrapped in its surrounding quotes (as produced by
+    ///   the lexer) is unescaped into its clean text via `unescape`.
+    ///
     /// # Examples
     /// ```
     /// assert_eq!(JsonValue::from_string("true"), JsonValue::Bool(true));
+    /// assert_eq!(JsonValue::from_string("null"), JsonValue::Null);
     /// assert_eq!(JsonValue::from_string("42"), JsonValue::Int(42));
     /// assert_eq!(JsonValue::from_string("3.14"), JsonValue::Float(3.14));
     /// assert_eq!(JsonValue::from_string("hello"), JsonValue::String("hello".to_string()));
     /// ```
-    pub(crate) fn parse(value: &str) -> JsonValue {
+    pub(crate) fn parse(value: &str) -> JsonResult<JsonValue> {
         if value == "true" {
-            JsonValue::Bool(true)
+            Ok(JsonValue::Bool(true))
         } else if value == "false" {
-            JsonValue::Bool(false)
-        } else if let Ok(as_int) = value.parse::<i32>() {
-            JsonValue::Int(as_int)
-        } else if let Ok(as_float) = value.parse::<f32>() {
-            JsonValue::Float(as_float)
+            Ok(JsonValue::Bool(false))
+        } else if value == "null" {
+            Ok(JsonValue::Null)
+        } else if let Ok(as_int) = value.parse::<i64>() {
+            Ok(JsonValue::Int(as_int))
+        } else if let Ok(as_float) = value.parse::<f64>() {
+            Ok(JsonValue::Float(as_float))
+        } else if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+            unescape(value).map(JsonValue::String)
         } else {
-            JsonValue::String(value.to_string())
+            Ok(JsonValue::String(value.to_string()))
         }
     }
 
@@ -48,20 +132,32 @@ impl JsonValue {
     /// conflict with `TryInto<String>`.
     ///
     /// # Notes
-    /// - `JsonValue::String` returns the inner string directly.
+    /// - `JsonValue::String` is escaped and re-quoted, since the result
+    ///   must stand in as a `Value` token's raw lexeme.
     /// - Other types are converted using their `to_string()` implementation.
-    /// - `JsonValue::Array` is currently unimplemented.
+    /// - `JsonValue::Array`/`JsonValue::Object` recursively render their
+    ///   elements/entries into a single JSON-text blob.
     ///
     /// # Examples
     /// ```
     /// assert_eq!(JsonValue::Int(42).to_string_force(), "42");
     /// assert_eq!(JsonValue::Bool(true).to_string_force(), "true");
-    /// assert_eq!(JsonValue::String("hello".into()).to_string_force(), "hello");
+    /// assert_eq!(JsonValue::String("hello".into()).to_string_force(), "\"hello\"");
     /// ```
     pub(crate) fn to_string_force(self) -> String {
         match self {
-            JsonValue::String(value) => value,
-            JsonValue::Array(_) => todo!(),
+            JsonValue::Null => "null".to_string(),
+            JsonValue::String(value) => quote_key(&value),
+            JsonValue::Array(values) => format!("[{}]",
+                values.into_iter()
+                    .map(JsonValue::to_string_force)
+                    .collect::<Vec<_>>()
+                    .join(",")),
+            JsonValue::Object(entries) => format!("{{{}}}",
+                entries.into_iter()
+                    .map(|(key, value)| format!("{}:{}", quote_key(&key), value.to_string_force()))
+                    .collect::<Vec<_>>()
+                    .join(",")),
             JsonValue::Int(value) => value.to_string(),
             JsonValue::Float(value) => value.to_string(),
             JsonValue::Bool(value) => value.to_string(),
@@ -71,18 +167,18 @@ impl JsonValue {
 
 impl From<&str> for JsonValue {
     fn from(value: &str) -> Self {
-        JsonValue::String(format!("\"{}\"", value))
+        JsonValue::String(value.to_string())
     }
 }
 
-impl From<i32> for JsonValue {
-    fn from(value: i32) -> Self {
+impl From<i64> for JsonValue {
+    fn from(value: i64) -> Self {
         JsonValue::Int(value)
     }
 }
 
-impl From<f32> for JsonValue {
-    fn from(value: f32) -> Self {
+impl From<f64> for JsonValue {
+    fn from(value: f64) -> Self {
         JsonValue::Float(value)
     }
 }
@@ -93,13 +189,11 @@ impl From<bool> for JsonValue {
     }
 }
 
-/* todo
-impl From<Array> for JsonValue {
-    fn from(value: Array) -> Self {
-        todo!()
+impl From<Vec<JsonValue>> for JsonValue {
+    fn from(value: Vec<JsonValue>) -> Self {
+        JsonValue::Array(value)
     }
 }
-*/
 
 impl TryInto<String> for JsonValue {
     type Error = JsonError;
@@ -112,10 +206,10 @@ impl TryInto<String> for JsonValue {
     }
 }
 
-impl TryInto<i32> for JsonValue {
+impl TryInto<i64> for JsonValue {
     type Error = JsonError;
-    
-    fn try_into(self) -> Result<i32, Self::Error> {
+
+    fn try_into(self) -> Result<i64, Self::Error> {
         match self {
             JsonValue::Int(value) => Ok(value),
             _ => Err(JsonError::JsonValueIsNotInteger),
@@ -123,10 +217,10 @@ impl TryInto<i32> for JsonValue {
     }
 }
 
-impl TryInto<f32> for JsonValue {
+impl TryInto<f64> for JsonValue {
     type Error = JsonError;
-    
-    fn try_into(self) -> Result<f32, Self::Error> {
+
+    fn try_into(self) -> Result<f64, Self::Error> {
         match self {
             JsonValue::Float(value) => Ok(value),
             _ => Err(JsonError::JsonValueIsNotFloat),
@@ -136,7 +230,7 @@ impl TryInto<f32> for JsonValue {
 
 impl TryInto<bool> for JsonValue {
     type Error = JsonError;
-    
+
     fn try_into(self) -> Result<bool, Self::Error> {
         match self {
             JsonValue::Bool(value) => Ok(value),
@@ -145,6 +239,112 @@ impl TryInto<bool> for JsonValue {
     }
 }
 
+impl TryInto<Vec<JsonValue>> for JsonValue {
+    type Error = JsonError;
+
+    fn try_into(self) -> Result<Vec<JsonValue>, Self::Error> {
+        match self {
+            JsonValue::Array(values) => Ok(values),
+            _ => Err(JsonError::JsonValueIsNotArray),
+        }
+    }
+}
+
+/// Lets any `TryInto<T>` target also be read as `Option<T>`, with
+/// `JsonValue::Null` mapping to `None` instead of an error.
+impl<T> TryInto<Option<T>> for JsonValue
+where
+    JsonValue: TryInto<T, Error = JsonError>,
+{
+    type Error = JsonError;
+
+    fn try_into(self) -> Result<Option<T>, Self::Error> {
+        match self {
+            JsonValue::Null => Ok(None),
+            other => other.try_into().map(Some),
+        }
+    }
+}
+
+/// A single segment of a parsed JSONPath expression.
+#[derive(Debug, Clone, PartialEq)]
+enum Selector {
+    Root,
+    Child(String),
+    Index(usize),
+    Slice(Option<usize>, Option<usize>),
+    Wildcard,
+    RecursiveDescent,
+}
+
+/// Reads a JSONPath string into a sequence of `Selector`s.
+///
+/// Supports `$` root, `.key` / `['key']` child access, `[n]` array index,
+/// `[start:end]` array slices, `[*]` / `.*` wildcard, and `..` recursive
+/// descent (with the name/selector that follows `..` applied to every
+/// descendant it queues).
+fn parse_path(path: &str) -> Vec<Selector> {
+    let chars: Vec<char> = path.chars().collect();
+    let mut selectors = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '$' => {
+                selectors.push(Selector::Root);
+                i += 1;
+            }
+            '.' if chars.get(i + 1) == Some(&'.') => {
+                selectors.push(Selector::RecursiveDescent);
+                i += 2;
+            }
+            '.' if chars.get(i + 1) == Some(&'*') => {
+                selectors.push(Selector::Wildcard);
+                i += 2;
+            }
+            '.' => {
+                i += 1;
+                let start = i;
+                while i < chars.len() && chars[i] != '.' && chars[i] != '[' { i += 1; }
+                selectors.push(Selector::Child(chars[start..i].iter().collect()));
+            }
+            '[' => {
+                i += 1;
+                if chars.get(i) == Some(&'*') {
+                    selectors.push(Selector::Wildcard);
+                    i += 1;
+                } else if chars.get(i) == Some(&'\'') {
+                    i += 1;
+                    let start = i;
+                    while i < chars.len() && chars[i] != '\'' { i += 1; }
+                    selectors.push(Selector::Child(chars[start..i].iter().collect()));
+                    i += 1;
+                } else {
+                    let start = i;
+                    while i < chars.len() && chars[i] != ']' { i += 1; }
+                    let spec: String = chars[start..i].iter().collect();
+
+                    selectors.push(match spec.split_once(':') {
+                        Some((lo, hi)) => Selector::Slice(lo.parse().ok(), hi.parse().ok()),
+                        None => Selector::Index(spec.parse().unwrap_or(0)),
+                    });
+                }
+
+                // Skip the closing `]`.
+                if chars.get(i) == Some(&']') { i += 1; }
+            }
+            // A bareword name directly following `..` (e.g. `$..book`).
+            _ => {
+                let start = i;
+                while i < chars.len() && chars[i] != '.' && chars[i] != '[' { i += 1; }
+                selectors.push(Selector::Child(chars[start..i].iter().collect()));
+            }
+        }
+    }
+
+    selectors
+}
+
 pub struct Json {
     tokens: Vec<Token>,
 }
@@ -180,7 +380,15 @@ impl Json {
             _ => (),
         };
     }
-   
+
+    /// The line/column of the token at `i`, or of the last token in the
+    /// stream if `i` is out of bounds, for use in positioned errors.
+    fn position_at(&self, i: usize) -> (usize, usize) {
+        self.tokens.get(i).or(self.tokens.last())
+            .map(|token| (token.line(), token.col()))
+            .unwrap_or((0, 0))
+    }
+
     /// Find a specific key token index by using keys path.
     fn find_key_token_index<'a>(&self, keys: &[&'a str]) -> JsonResult<usize> {
         if keys.is_empty() { return Err(JsonError::NoPathProvided); }
@@ -197,37 +405,233 @@ impl Json {
             if token.token_type() == TokenType::Key && nested_level == key_found {
                 let key_lexeme = token.lexeme().as_ref().unwrap();
 
-                // Ignore the quotes in key lexeme (\"key_lexeme\") -> (key_lexeme).
-                if &key_lexeme[1..key_lexeme.len() - 1] == keys[key_found] {
+                if unescape_lossy(key_lexeme) == keys[key_found] {
                     key_found += 1;
 
                     if key_found == keys.len() {
                         return Ok(i);
                     }
                 }
-            } 
+            }
         }
 
-        Err(JsonError::InvalidPath)
+        let (line, col) = self.position_at(self.tokens.len());
+        Err(JsonError::InvalidPath(line, col))
     }
 
     pub fn get<'a>(&self, keys: &[&'a str]) -> JsonResult<JsonValue> {
-        self.tokens
-            .get(self.find_key_token_index(keys)? + 2)
-            .ok_or(JsonError::InvalidJson)
-            .and_then(|value_token| {
-                if value_token.token_type() == TokenType::OpeningBrace {
-                    Err(JsonError::InvalidPath)
-                } else {
-                    Ok(JsonValue::parse(value_token.lexeme().as_ref().unwrap()))
+        let value_index = self.find_key_token_index(keys)? + 2;
+        self.materialize(value_index).map(|(value, _)| value)
+    }
+
+    /// Materializes the whole document into a single `JsonValue`, for
+    /// callers (such as `Decode`) that want the full recursive tree rather
+    /// than one value looked up by path.
+    pub fn to_value(&self) -> JsonResult<JsonValue> {
+        self.materialize(0).map(|(value, _)| value)
+    }
+
+    /// Collects `(key, value_index)` for every direct child of the object
+    /// whose `{` sits at `opening_brace`, using the existing bracket
+    /// nesting counter to skip over nested objects/arrays.
+    fn object_children(&self, opening_brace: usize) -> Vec<(String, usize)> {
+        let mut children = vec![];
+        let mut nested_level = 1;
+
+        for i in (opening_brace + 1)..self.tokens.len() {
+            let token = &self.tokens[i];
+            Self::update_nested_level_include_brackets(&mut nested_level, token);
+
+            if nested_level == 0 { break; }
+
+            if nested_level == 1 && token.token_type() == TokenType::Key {
+                let key = unescape_lossy(token.lexeme().as_ref().unwrap());
+                children.push((key, i + 2));
+            }
+        }
+
+        children
+    }
+
+    /// Collects the token index of every top-level element of the array
+    /// whose `[` sits at `left_bracket`, by counting `Separator` tokens at
+    /// nesting depth 1 (relative to the bracket).
+    fn array_elements(&self, left_bracket: usize) -> Vec<usize> {
+        let mut elements = vec![];
+        let mut nested_level = 1;
+        let mut element_start = left_bracket + 1;
+
+        for i in (left_bracket + 1)..self.tokens.len() {
+            let token = &self.tokens[i];
+            Self::update_nested_level_include_brackets(&mut nested_level, token);
+
+            if nested_level == 0 {
+                if element_start < i { elements.push(element_start); }
+                break;
+            }
+
+            if nested_level == 1 && token.token_type() == TokenType::Separator {
+                elements.push(element_start);
+                element_start = i + 1;
+            }
+        }
+
+        elements
+    }
+
+    /// Token index of every direct child of `node` - an object's values or
+    /// an array's elements. Empty if `node` is neither.
+    fn all_children(&self, node: usize) -> Vec<usize> {
+        match self.tokens.get(node).map(|t| t.token_type()) {
+            Some(TokenType::OpeningBrace) => self.object_children(node)
+                .into_iter().map(|(_, i)| i).collect(),
+            Some(TokenType::LeftBracket) => self.array_elements(node),
+            _ => vec![],
+        }
+    }
+
+    /// Token index of every node reachable from `node`, at any depth, in
+    /// document order (each child before its own descendants, left to
+    /// right).
+    fn descendants(&self, node: usize) -> Vec<usize> {
+        let mut result = vec![];
+
+        for child in self.all_children(node) {
+            result.push(child);
+            result.extend(self.descendants(child));
+        }
+
+        result
+    }
+
+    /// Evaluates one `Selector` against the current set of matched node
+    /// positions, producing the next set.
+    fn apply_selector(&self, worklist: &[usize], selector: &Selector) -> Vec<usize> {
+        match selector {
+            Selector::Root => vec![0],
+            Selector::Wildcard => worklist.iter()
+                .flat_map(|&node| self.all_children(node))
+                .collect(),
+            Selector::Child(name) => worklist.iter()
+                .filter(|&&node| self.tokens.get(node).map(|t| t.token_type()) == Some(TokenType::OpeningBrace))
+                .filter_map(|&node| self.object_children(node).into_iter()
+                    .find(|(key, _)| key == name)
+                    .map(|(_, i)| i))
+                .collect(),
+            Selector::Index(n) => worklist.iter()
+                .filter(|&&node| self.tokens.get(node).map(|t| t.token_type()) == Some(TokenType::LeftBracket))
+                .filter_map(|&node| self.array_elements(node).get(*n).copied())
+                .collect(),
+            Selector::Slice(start, end) => worklist.iter()
+                .filter(|&&node| self.tokens.get(node).map(|t| t.token_type()) == Some(TokenType::LeftBracket))
+                .flat_map(|&node| {
+                    let elements = self.array_elements(node);
+                    let start = (*start).unwrap_or(0).min(elements.len());
+                    let end = (*end).unwrap_or(elements.len()).clamp(start, elements.len());
+                    elements[start..end].to_vec()
+                })
+                .collect(),
+            Selector::RecursiveDescent => {
+                let mut expanded = worklist.to_vec();
+
+                for &node in worklist {
+                    for descendant in self.descendants(node) {
+                        if !expanded.contains(&descendant) {
+                            expanded.push(descendant);
+                        }
+                    }
                 }
-            })
+
+                expanded
+            }
+        }
+    }
+
+    /// Evaluates a JSONPath expression (`$`, `.key`, `['key']`, `[n]`,
+    /// `[start:end]`, `[*]`/`.*`, `..`) against the token stream, returning
+    /// every matching value. A path that simply matches nothing yields an
+    /// empty `Vec` rather than an error.
+    ///
+    /// # Examples
+    /// ```
+    /// let json = Json::from_string(r#"{"a":{"b":[1,2,3]}}"#);
+    /// assert_eq!(json.query("$.a.b[1:]").unwrap(), vec![JsonValue::Int(2), JsonValue::Int(3)]);
+    /// ```
+    pub fn query(&self, path: &str) -> JsonResult<Vec<JsonValue>> {
+        let mut worklist = vec![0usize];
+
+        for selector in parse_path(path) {
+            worklist = self.apply_selector(&worklist, &selector);
+        }
+
+        worklist.into_iter()
+            .map(|node| self.materialize(node).map(|(value, _)| value))
+            .collect()
+    }
+
+    /// Builds a `JsonValue` starting at token index `start`, recursing into
+    /// nested objects/arrays. Returns the value along with the index of the
+    /// token right after it, so callers can keep walking sibling values.
+    fn materialize(&self, start: usize) -> JsonResult<(JsonValue, usize)> {
+        let token = self.tokens.get(start).ok_or_else(|| {
+            let (line, col) = self.position_at(start);
+            JsonError::InvalidJson(line, col)
+        })?;
+
+        match token.token_type() {
+            TokenType::OpeningBrace => self.materialize_object(start),
+            TokenType::LeftBracket => self.materialize_array(start),
+            _ => Ok((JsonValue::parse(token.lexeme().as_ref().unwrap())?, start + 1)),
+        }
+    }
+
+    fn materialize_object(&self, start: usize) -> JsonResult<(JsonValue, usize)> {
+        let mut i = start + 1;
+        let mut entries = vec![];
+
+        while self.tokens.get(i).map(|t| t.token_type()) != Some(TokenType::ClosingBrace) {
+            let key_token = self.tokens.get(i).ok_or_else(|| {
+                let (line, col) = self.position_at(i);
+                JsonError::InvalidJson(line, col)
+            })?;
+            let key = unescape(key_token.lexeme().as_ref().unwrap())?;
+
+            let (value, next) = self.materialize(i + 2)?;
+            entries.push((key, value));
+            i = next;
+
+            if self.tokens.get(i).map(|t| t.token_type()) == Some(TokenType::Separator) {
+                i += 1;
+            }
+        }
+
+        Ok((JsonValue::Object(entries), i + 1))
+    }
+
+    fn materialize_array(&self, start: usize) -> JsonResult<(JsonValue, usize)> {
+        let mut i = start + 1;
+        let mut values = vec![];
+
+        while self.tokens.get(i).map(|t| t.token_type()) != Some(TokenType::RightBracket) {
+            let (value, next) = self.materialize(i)?;
+            values.push(value);
+            i = next;
+
+            if self.tokens.get(i).map(|t| t.token_type()) == Some(TokenType::Separator) {
+                i += 1;
+            }
+        }
+
+        Ok((JsonValue::Array(values), i + 1))
     }
 
     #[inline]
     fn is_key_value_an_object(&self, key_index: usize) -> JsonResult<bool> {
         self.tokens.get(key_index + 2)
-            .ok_or(JsonError::InvalidJson)
+            .ok_or_else(|| {
+                let (line, col) = self.position_at(key_index + 2);
+                JsonError::InvalidJson(line, col)
+            })
             .and_then(|token| {
                 Ok(token.token_type() == TokenType::OpeningBrace)
             })
@@ -256,8 +660,11 @@ impl Json {
             Some(token) if token.token_type() == TokenType::ClosingBrace => (),
             Some(_) => self.tokens.insert(
                 insert_at + tokens_len, Token::no_lexeme(TokenType::Separator)),
-            None => return Err(JsonError::InvalidJson),
-        } 
+            None => {
+                let (line, col) = self.position_at(insert_at + tokens_len);
+                return Err(JsonError::InvalidJson(line, col));
+            }
+        }
 
         Ok(())
     }
@@ -269,7 +676,7 @@ impl Json {
         let value_as_string: String = value.to_string_force();
 
         self.insert_tokens(keys, vec![
-            Token::new(&format!("\"{}\"", key), TokenType::Key),
+            Token::new(&quote_key(key), TokenType::Key),
             Token::no_lexeme(TokenType::Assigner),
             Token::new(&value_as_string, TokenType::Value)
         ], true)
@@ -278,7 +685,7 @@ impl Json {
     #[inline]
     pub fn insert_object(&mut self, keys: &[&str], key: &str) -> JsonResult<()> {
         self.insert_tokens(keys, vec![
-            Token::new(&format!("\"{}\"", key), TokenType::Key),
+            Token::new(&quote_key(key), TokenType::Key),
             Token::no_lexeme(TokenType::Assigner),
             Token::no_lexeme(TokenType::OpeningBrace),
             Token::no_lexeme(TokenType::ClosingBrace),
@@ -347,7 +754,8 @@ impl Json {
                     buf.push(bracket);
                     buf.push('\n');
                 } else {
-                    return Err(JsonError::InvalidJson);
+                    let (line, col) = self.position_at(i);
+                    return Err(JsonError::InvalidJson(line, col));
                 }
             }
         }
@@ -438,17 +846,17 @@ impl JsonBuilder {
     fn add_separator_if_needed(&mut self) {
         if matches!(
             self.tokens.last().map(|token| token.token_type()),
-            Some(TokenType::Value | TokenType::ClosingBrace)) 
+            Some(TokenType::Value | TokenType::ClosingBrace | TokenType::RightBracket))
         {
             self.tokens.push(Token::no_lexeme(TokenType::Separator));
-        } 
+        }
     }
 
     pub fn object(mut self, name: &str) -> Self {
         self.add_separator_if_needed();
 
         self.tokens.extend([
-            Token::new(&format!("\"{}\"", name), TokenType::Key),
+            Token::new(&quote_key(name), TokenType::Key),
             Token::no_lexeme(TokenType::Assigner),
             Token::no_lexeme(TokenType::OpeningBrace)
         ]);
@@ -459,13 +867,31 @@ impl JsonBuilder {
         self.add_separator_if_needed();
 
         self.tokens.extend([
-            Token::new(&format!("\"{}\"", key), TokenType::Key),
+            Token::new(&quote_key(key), TokenType::Key),
             Token::no_lexeme(TokenType::Assigner),
             Token::new(&value.into().to_string_force(), TokenType::Value)
         ]);
         self
     }
 
+    pub fn array(mut self, key: &str, values: Vec<JsonValue>) -> Self {
+        self.add_separator_if_needed();
+
+        self.tokens.extend([
+            Token::new(&quote_key(key), TokenType::Key),
+            Token::no_lexeme(TokenType::Assigner),
+            Token::no_lexeme(TokenType::LeftBracket),
+        ]);
+
+        for (i, value) in values.into_iter().enumerate() {
+            if i > 0 { self.tokens.push(Token::no_lexeme(TokenType::Separator)); }
+            self.tokens.push(Token::new(&value.to_string_force(), TokenType::Value));
+        }
+
+        self.tokens.push(Token::no_lexeme(TokenType::RightBracket));
+        self
+    }
+
     #[inline]
     pub fn object_end(mut self) -> Self {
         self.tokens.push(Token::no_lexeme(TokenType::ClosingBrace));
@@ -1,60 +1,91 @@
 use crate::token::{Token, TokenType};
-use std::{fs, io};
+use std::{fs, io, iter::Peekable, str::Chars};
 
-fn handle_closing_brace(buf: &mut Vec<Token>, lexeme: &mut String) {
+fn handle_closing_brace(buf: &mut Vec<Token>, lexeme: &mut String, line: usize, col: usize) {
     if !lexeme.is_empty() {
-        buf.push(Token::new(lexeme, TokenType::Value));
+        buf.push(Token::new(lexeme, TokenType::Value).with_position(line, col));
     }
-    buf.push(Token::no_lexeme(TokenType::ClosingBrace));
+    buf.push(Token::no_lexeme(TokenType::ClosingBrace).with_position(line, col));
 
     lexeme.clear();
 }
 
-fn handle_right_bracket(buf: &mut Vec<Token>, lexeme: &mut String) {
+fn handle_right_bracket(buf: &mut Vec<Token>, lexeme: &mut String, line: usize, col: usize) {
     if !lexeme.is_empty() {
-        buf.push(Token::new(lexeme, TokenType::Value));
+        buf.push(Token::new(lexeme, TokenType::Value).with_position(line, col));
     }
-    buf.push(Token::no_lexeme(TokenType::RightBracket));
+    buf.push(Token::no_lexeme(TokenType::RightBracket).with_position(line, col));
 
     lexeme.clear();
 }
 
-fn handle_assigner(buf: &mut Vec<Token>, lexeme: &mut String) {
-    buf.push(Token::new(lexeme, TokenType::Key));
-    buf.push(Token::no_lexeme(TokenType::Assigner));
+fn handle_assigner(buf: &mut Vec<Token>, lexeme: &mut String, line: usize, col: usize) {
+    buf.push(Token::new(lexeme, TokenType::Key).with_position(line, col));
+    buf.push(Token::no_lexeme(TokenType::Assigner).with_position(line, col));
 
     lexeme.clear();
 }
 
-fn handle_separator(buf: &mut Vec<Token>, lexeme: &mut String) {
+fn handle_separator(buf: &mut Vec<Token>, lexeme: &mut String, line: usize, col: usize) {
     if lexeme.len() > 0 {
-        buf.push(Token::new(lexeme, TokenType::Value));
+        buf.push(Token::new(lexeme, TokenType::Value).with_position(line, col));
     }
-    buf.push(Token::no_lexeme(TokenType::Separator));
+    buf.push(Token::no_lexeme(TokenType::Separator).with_position(line, col));
 
     lexeme.clear();
 }
 
-fn handle_space(lexeme: &mut String) {
-    if lexeme.len() != 0 && lexeme.chars().next().unwrap() == '\"' {
-        lexeme.push(' ');
+/// Consumes a quoted JSON string starting right after the opening `"`,
+/// copying its raw source text - escape sequences untouched, quotes
+/// included - into `lexeme`. Escapes are only resolved later, when the
+/// token is turned into a `JsonValue::String` (see `json::unescape`);
+/// keeping the raw form here means `Json::to_string`/`to_string_format`
+/// can re-emit the token verbatim without re-escaping it.
+fn lex_string(chars: &mut Peekable<Chars>, lexeme: &mut String, line: &mut usize, col: &mut usize) {
+    lexeme.push('"');
+
+    while let Some(ch) = chars.next() {
+        *col += 1;
+        if ch == '\n' { *line += 1; *col = 0; }
+
+        match ch {
+            '"' => break,
+            '\\' => {
+                lexeme.push('\\');
+
+                if let Some(escaped) = chars.next() {
+                    *col += 1;
+                    if escaped == '\n' { *line += 1; *col = 0; }
+                    lexeme.push(escaped);
+                }
+            }
+            other => lexeme.push(other),
+        }
     }
+
+    lexeme.push('"');
 }
 
 pub fn lex(data: &str) -> Vec<Token> {
     let mut result: Vec<Token> = vec![];
     let mut lexeme = String::with_capacity(36);
+    let mut chars = data.chars().peekable();
+    let mut line = 1usize;
+    let mut col = 0usize;
+
+    while let Some(ch) = chars.next() {
+        col += 1;
 
-    for ch in data.chars() {
         match ch {
-            '{' => result.push(Token::no_lexeme(TokenType::OpeningBrace)),
-            '}' => handle_closing_brace(&mut result, &mut lexeme),
-            '[' => result.push(Token::no_lexeme(TokenType::LeftBracket)),
-            ']' => handle_right_bracket(&mut result, &mut lexeme), 
-            ':' => handle_assigner(&mut result, &mut lexeme),
-            ',' => handle_separator(&mut result, &mut lexeme),
-            ' ' => handle_space(&mut lexeme), 
-            '\n' | '\r' | '\t' => (),
+            '{' => result.push(Token::no_lexeme(TokenType::OpeningBrace).with_position(line, col)),
+            '}' => handle_closing_brace(&mut result, &mut lexeme, line, col),
+            '[' => result.push(Token::no_lexeme(TokenType::LeftBracket).with_position(line, col)),
+            ']' => handle_right_bracket(&mut result, &mut lexeme, line, col),
+            ':' => handle_assigner(&mut result, &mut lexeme, line, col),
+            ',' => handle_separator(&mut result, &mut lexeme, line, col),
+            '"' => lex_string(&mut chars, &mut lexeme, &mut line, &mut col),
+            '\n' => { line += 1; col = 0; }
+            ' ' | '\r' | '\t' => (),
             _ => lexeme.push(ch),
         }
     }
@@ -0,0 +1,110 @@
+use crate::parser::json::JsonValue;
+
+/// Escapes `value` per the standard JSON two-character escapes (`\" \\ \b
+/// \f \n \r \t`), falls back to `\uXXXX` for any other control character,
+/// and wraps the result in quotes.
+fn escape_into(value: &str, buf: &mut String) {
+    buf.push('"');
+
+    for ch in value.chars() {
+        match ch {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\u{8}' => buf.push_str("\\b"),
+            '\u{c}' => buf.push_str("\\f"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            other if (other as u32) < 0x20 => {
+                buf.push_str(&format!("\\u{:04x}", other as u32));
+            }
+            _ => buf.push(ch),
+        }
+    }
+
+    buf.push('"');
+}
+
+/// Serializes a `JsonValue` back into compact JSON text.
+///
+/// `parser::json::Json` has no `to_string` of its own (unlike the
+/// top-level `Json`, it never builds a token stream meant to be
+/// re-emitted verbatim), so this walks a standalone `JsonValue` - for
+/// example one returned by `Json::get` - and writes it from scratch.
+pub fn to_string(value: &JsonValue) -> String {
+    let mut buf = String::new();
+    write_value(value, &mut buf);
+    buf
+}
+
+fn write_value(value: &JsonValue, buf: &mut String) {
+    match value {
+        JsonValue::Null => buf.push_str("null"),
+        JsonValue::Int(v) => buf.push_str(&v.to_string()),
+        JsonValue::Float(v) => buf.push_str(&v.to_string()),
+        JsonValue::Bool(v) => buf.push_str(&v.to_string()),
+        JsonValue::String(v) => escape_into(v, buf),
+        JsonValue::Array(values) => {
+            buf.push('[');
+
+            for (i, value) in values.iter().enumerate() {
+                if i != 0 { buf.push(','); }
+                write_value(value, buf);
+            }
+
+            buf.push(']');
+        }
+    }
+}
+
+fn write_value_pretty(value: &JsonValue, buf: &mut String, indent: usize, level: usize) {
+    match value {
+        JsonValue::Array(values) if !values.is_empty() => {
+            buf.push_str("[\n");
+
+            for (i, value) in values.iter().enumerate() {
+                if i != 0 { buf.push_str(",\n"); }
+                buf.extend(std::iter::repeat(' ').take(indent * (level + 1)));
+                write_value_pretty(value, buf, indent, level + 1);
+            }
+
+            buf.push('\n');
+            buf.extend(std::iter::repeat(' ').take(indent * level));
+            buf.push(']');
+        }
+        _ => write_value(value, buf),
+    }
+}
+
+/// Serializes a `JsonValue` into human-readable JSON text, indenting nested
+/// arrays by `indent` spaces per level.
+pub fn to_string_pretty(value: &JsonValue, indent: usize) -> String {
+    let mut buf = String::new();
+    write_value_pretty(value, &mut buf, indent, 0);
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_string_encodes_scalars() {
+        assert_eq!(to_string(&JsonValue::Null), "null");
+        assert_eq!(to_string(&JsonValue::Int(42)), "42");
+        assert_eq!(to_string(&JsonValue::Bool(true)), "true");
+        assert_eq!(to_string(&JsonValue::String("a\"b".to_string())), r#""a\"b""#);
+    }
+
+    #[test]
+    fn to_string_encodes_a_nested_array() {
+        let value = JsonValue::Array(vec![JsonValue::Int(1), JsonValue::Array(vec![JsonValue::Bool(false)])]);
+        assert_eq!(to_string(&value), "[1,[false]]");
+    }
+
+    #[test]
+    fn to_string_pretty_indents_nested_arrays() {
+        let value = JsonValue::Array(vec![JsonValue::Int(1), JsonValue::Int(2)]);
+        assert_eq!(to_string_pretty(&value, 2), "[\n  1,\n  2\n]");
+    }
+}
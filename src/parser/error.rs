@@ -0,0 +1,28 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum JsonError {
+    #[error("An empty path is an invalid path.")]
+    NoPathProvided,
+
+    #[error("Invalid path to value at line {0}, column {1}.")]
+    InvalidPath(usize, usize),
+
+    #[error("Invalid Json at line {0}, column {1}.")]
+    InvalidJson(usize, usize),
+}
+
+impl PartialEq for JsonError {
+    fn eq(&self, other: &Self) -> bool {
+        use JsonError::*;
+
+        matches!(
+            (self, other),
+            (NoPathProvided, NoPathProvided)
+                | (InvalidPath(_, _), InvalidPath(_, _))
+                | (InvalidJson(_, _), InvalidJson(_, _))
+        )
+    }
+}
+
+pub type JsonResult<T> = Result<T, JsonError>;
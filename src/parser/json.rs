@@ -1,17 +1,100 @@
-use crate::parser::{lexer::{lex, lex_from_file}, token::{Token, TokenType}};
+use crate::parser::{
+    error::{JsonError, JsonResult},
+    lexer::{lex, lex_from_file},
+    token::{Token, TokenType},
+};
 use std::io;
 
 #[derive(Debug, Clone, PartialEq)]
 #[repr(u8)]
 pub enum JsonValue {
-    Unknown,
-    Int(i32),
-    Float(f32),
+    Null,
+    Int(i64),
+    Float(f64),
     Bool(bool),
     String(String),
     Array(Vec<JsonValue>),
 }
 
+/// A single segment of a tokenized JSONPath expression.
+#[derive(Debug, Clone, PartialEq)]
+enum PathSegment {
+    Root,
+    Child(String),
+    Index(usize),
+    Wildcard,
+    RecursiveDescent(String),
+}
+
+/// Reads a JSONPath string into a sequence of `PathSegment`s.
+///
+/// Supports `$` root, `.key` / `['key']` child access, `[n]` array index,
+/// `[*]` / `.*` wildcard, and `..key` recursive descent.
+fn tokenize_path(path: &str) -> Vec<PathSegment> {
+    let chars: Vec<char> = path.chars().collect();
+    let mut segments = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '$' => {
+                segments.push(PathSegment::Root);
+                i += 1;
+            }
+            '.' if chars.get(i + 1) == Some(&'.') => {
+                i += 2;
+                let start = i;
+                while i < chars.len() && chars[i] != '.' && chars[i] != '[' { i += 1; }
+                segments.push(PathSegment::RecursiveDescent(chars[start..i].iter().collect()));
+            }
+            '.' if chars.get(i + 1) == Some(&'*') => {
+                segments.push(PathSegment::Wildcard);
+                i += 2;
+            }
+            '.' => {
+                i += 1;
+                let start = i;
+                while i < chars.len() && chars[i] != '.' && chars[i] != '[' { i += 1; }
+                segments.push(PathSegment::Child(chars[start..i].iter().collect()));
+            }
+            '[' => {
+                i += 1;
+                if chars.get(i) == Some(&'*') {
+                    segments.push(PathSegment::Wildcard);
+                    i += 1;
+                } else if chars.get(i) == Some(&'\'') {
+                    i += 1;
+                    let start = i;
+                    while i < chars.len() && chars[i] != '\'' { i += 1; }
+                    segments.push(PathSegment::Child(chars[start..i].iter().collect()));
+                    i += 1;
+                } else {
+                    let start = i;
+                    while i < chars.len() && chars[i] != ']' { i += 1; }
+                    let index: String = chars[start..i].iter().collect();
+                    segments.push(PathSegment::Index(index.parse().unwrap_or(0)));
+                }
+                // Skip the closing `]`.
+                if chars.get(i) == Some(&']') { i += 1; }
+            }
+            _ => i += 1,
+        }
+    }
+
+    segments
+}
+
+/// Strips the surrounding quotes off a lexed `Key`/`Value` lexeme. Both
+/// keep their quotes through `lex` (escapes already resolved in place by
+/// `lex_string`), so comparing or materializing them needs this first.
+fn strip_quotes(lexeme: &str) -> &str {
+    if lexeme.len() >= 2 && lexeme.starts_with('"') && lexeme.ends_with('"') {
+        &lexeme[1..lexeme.len() - 1]
+    } else {
+        lexeme
+    }
+}
+
 pub struct Json {
     tokens: Vec<Token>,
 }
@@ -20,7 +103,7 @@ impl Json {
     #[inline]
     pub fn from_file(path: &str) -> io::Result<Json> {
         Ok(Json { tokens: lex_from_file(path)?, })
-    } 
+    }
 
     #[inline]
     pub fn from_string(data: &str) -> Json {
@@ -32,26 +115,44 @@ impl Json {
             JsonValue::Bool(true)
         } else if lexeme == "false" {
             JsonValue::Bool(false)
-        } else if let Ok(as_int) = lexeme.parse::<i32>() {
+        } else if lexeme == "null" {
+            JsonValue::Null
+        } else if let Ok(as_int) = lexeme.parse::<i64>() {
             JsonValue::Int(as_int)
-        } else if let Ok(as_float) = lexeme.parse::<f32>() {
+        } else if let Ok(as_float) = lexeme.parse::<f64>() {
             JsonValue::Float(as_float)
         } else {
-            JsonValue::String(lexeme.to_string())
+            JsonValue::String(strip_quotes(lexeme).to_string())
         }
     }
 
     fn update_nested_level(buf: &mut usize, current_token: &Token) {
         if current_token.token_type() == TokenType::OpeningBrace {
             *buf += 1;
-            println!("nested level inc: {}", *buf);
         } else if current_token.token_type() == TokenType::ClosingBrace {
             *buf -= 1;
-            println!("nested level dec: {}", *buf);
         }
     }
 
-    pub fn get<'a>(&self, keys: &[&'a str]) -> JsonValue {
+    fn update_nested_level_include_brackets(buf: &mut usize, current_token: &Token) {
+        match current_token.token_type() {
+            TokenType::OpeningBrace | TokenType::LeftBracket => *buf += 1,
+            TokenType::ClosingBrace | TokenType::RightBracket => *buf -= 1,
+            _ => (),
+        }
+    }
+
+    /// The line/column of the token at `i`, or of the last token in the
+    /// stream if `i` is out of bounds, for use in positioned errors.
+    fn position_at(&self, i: usize) -> (usize, usize) {
+        self.tokens.get(i).or(self.tokens.last())
+            .map(|token| (token.line(), token.col()))
+            .unwrap_or((0, 0))
+    }
+
+    pub fn get<'a>(&self, keys: &[&'a str]) -> JsonResult<JsonValue> {
+        if keys.is_empty() { return Err(JsonError::NoPathProvided); }
+
         let mut key_found = 0usize;
         let mut nested_level = 0usize;
 
@@ -63,17 +164,188 @@ impl Json {
 
             if token.token_type() == TokenType::Key
                 && nested_level == key_found
-                && token.lexeme().as_ref().unwrap() == keys[key_found] 
+                && token.lexeme().as_ref().map(|k| strip_quotes(k)) == Some(keys[key_found])
             {
                 key_found += 1;
 
                 if key_found == keys.len() {
-                    return Self::lexeme_to_val(
-                        self.tokens[i + 2].lexeme().as_ref().unwrap())
+                    return Ok(Self::lexeme_to_val(
+                        self.tokens[i + 2].lexeme().as_ref().unwrap()))
                 }
-            } 
+            }
         }
 
-        JsonValue::Unknown
+        let (line, col) = self.position_at(self.tokens.len());
+        Err(JsonError::InvalidPath(line, col))
+    }
+
+    /// Finds the token index of the `nth` direct child of the container
+    /// (an object's `Key`, or an array's element) starting at `container`.
+    /// For an object child this returns its value's token index; for an
+    /// array child it returns the element's own token index.
+    fn nth_child_index(&self, container: usize, nth: usize) -> Option<usize> {
+        let is_array = self.tokens[container].token_type() == TokenType::LeftBracket;
+        // `container` itself is the opening brace/bracket, already one level
+        // deep; direct children live at this starting level, not at 0.
+        let mut nested_level = 1;
+        let mut seen = 0;
+
+        for i in (container + 1)..self.tokens.len() {
+            let token = &self.tokens[i];
+            Self::update_nested_level_include_brackets(&mut nested_level, token);
+
+            if nested_level == 0 { break; }
+
+            let is_direct_child = nested_level == 1 && (
+                (!is_array && token.token_type() == TokenType::Key)
+                    || (is_array && matches!(self.tokens[i - 1].token_type(),
+                        TokenType::LeftBracket | TokenType::Separator))
+            );
+
+            if is_direct_child {
+                if seen == nth {
+                    return Some(if is_array { i } else { i + 2 });
+                }
+                seen += 1;
+            }
+        }
+
+        None
+    }
+
+    /// Collects the token index of every direct child value of the
+    /// container starting at `container`.
+    fn all_children(&self, container: usize) -> Vec<usize> {
+        let mut children = vec![];
+        let mut n = 0;
+
+        while let Some(index) = self.nth_child_index(container, n) {
+            children.push(index);
+            n += 1;
+        }
+
+        children
+    }
+
+    /// Collects the token index of every descendant `Key` token named
+    /// `name` under the container starting at `container`, at any depth.
+    fn recursive_children(&self, container: usize, name: &str) -> Vec<usize> {
+        let mut matches = vec![];
+        let mut nested_level = 0;
+
+        for i in (container + 1)..self.tokens.len() {
+            let token = &self.tokens[i];
+            Self::update_nested_level_include_brackets(&mut nested_level, token);
+
+            if nested_level == 0 { break; }
+
+            if token.token_type() == TokenType::Key
+                && token.lexeme().as_ref().map(|k| strip_quotes(k)) == Some(name)
+            {
+                matches.push(i + 2);
+            }
+        }
+
+        matches
+    }
+
+    /// Evaluates a JSONPath expression (`$`, `.key`, `['key']`, `[n]`,
+    /// `[*]`/`.*`, `..key`) against the token stream, returning every
+    /// matching value. Results that are themselves objects or arrays are
+    /// skipped, since this reader has no way to materialize a subtree.
+    pub fn query(&self, path: &str) -> JsonResult<Vec<JsonValue>> {
+        if path.is_empty() { return Err(JsonError::NoPathProvided); }
+
+        let mut worklist = vec![0usize];
+
+        for segment in tokenize_path(path) {
+            worklist = match segment {
+                PathSegment::Root => vec![0],
+                PathSegment::Wildcard => worklist.iter()
+                    .flat_map(|&i| self.all_children(i))
+                    .collect(),
+                PathSegment::Child(name) => worklist.iter()
+                    .filter_map(|&i| {
+                        self.all_children(i).into_iter().find(|&child| {
+                            self.tokens[child - 2].lexeme().as_ref()
+                                .map(|k| strip_quotes(k)) == Some(name.as_str())
+                        })
+                    })
+                    .collect(),
+                PathSegment::Index(n) => worklist.iter()
+                    .filter_map(|&i| self.nth_child_index(i, n))
+                    .collect(),
+                PathSegment::RecursiveDescent(name) => worklist.iter()
+                    .flat_map(|&i| self.recursive_children(i, &name))
+                    .collect(),
+            };
+        }
+
+        Ok(worklist.into_iter()
+            .filter_map(|i| self.tokens.get(i))
+            .filter(|token| token.token_type() == TokenType::Value)
+            .map(|token| Self::lexeme_to_val(token.lexeme().as_ref().unwrap()))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_decodes_an_escaped_string_value() {
+        let json = Json::from_string(r#"{"key": "a\"b"}"#);
+        assert_eq!(json.get(&["key"]), Ok(JsonValue::String("a\"b".to_string())));
+    }
+
+    #[test]
+    fn get_handles_structural_characters_inside_a_string_value() {
+        let json = Json::from_string(r#"{"key": "a,b:c{d}e"}"#);
+        assert_eq!(json.get(&["key"]), Ok(JsonValue::String("a,b:c{d}e".to_string())));
+    }
+
+    #[test]
+    fn query_widens_null_and_floats_nested_inside_an_array() {
+        let json = Json::from_string(r#"{"a": [null, 3.5, -7]}"#);
+        assert_eq!(json.query("$.a[0]"), Ok(vec![JsonValue::Null]));
+        assert_eq!(json.query("$.a[1]"), Ok(vec![JsonValue::Float(3.5)]));
+        assert_eq!(json.query("$.a[2]"), Ok(vec![JsonValue::Int(-7)]));
+    }
+
+    #[test]
+    fn get_still_resolves_plain_keys_and_values() {
+        let json = Json::from_string(r#"{"a": {"b": 1}}"#);
+        assert_eq!(json.get(&["a", "b"]), Ok(JsonValue::Int(1)));
+    }
+
+    #[test]
+    fn get_reports_the_position_of_a_missing_key() {
+        let json = Json::from_string("{\n  \"a\": 1\n}");
+        assert_eq!(json.get(&["missing"]), Err(JsonError::InvalidPath(3, 1)));
+    }
+
+    #[test]
+    fn query_reports_an_empty_path_as_an_error() {
+        let json = Json::from_string(r#"{"a": 1}"#);
+        assert_eq!(json.query(""), Err(JsonError::NoPathProvided));
+    }
+
+    #[test]
+    fn query_resolves_a_child_selector() {
+        let json = Json::from_string(r#"{"a": 1}"#);
+        assert_eq!(json.query("$.a"), Ok(vec![JsonValue::Int(1)]));
+    }
+
+    #[test]
+    fn query_resolves_an_array_index() {
+        let json = Json::from_string(r#"{"a": [1, 2, 3]}"#);
+        assert_eq!(json.query("$.a[1]"), Ok(vec![JsonValue::Int(2)]));
+    }
+
+    #[test]
+    fn query_resolves_a_wildcard_over_object_values() {
+        let json = Json::from_string(r#"{"a": 1, "b": 2}"#);
+        assert_eq!(json.query("$.*"), Ok(vec![JsonValue::Int(1), JsonValue::Int(2)]));
     }
 }
@@ -1,38 +1,130 @@
 use crate::parser::token::{Token, TokenType};
-use std::{fs, io};
+use std::{fs, io, iter::Peekable, str::Chars};
 
-fn handle_closing_brace(buf: &mut Vec<Token>, lexeme: &mut String) {
-    buf.push(Token::new(lexeme, TokenType::Value));
-    buf.push(Token::no_lexeme(TokenType::ClosingBrace));
+fn handle_closing_brace(buf: &mut Vec<Token>, lexeme: &mut String, line: usize, col: usize) {
+    if !lexeme.is_empty() {
+        buf.push(Token::new(lexeme, TokenType::Value).with_position(line, col));
+    }
+    buf.push(Token::no_lexeme(TokenType::ClosingBrace).with_position(line, col));
 
     lexeme.clear();
 }
 
-fn handle_assigner(buf: &mut Vec<Token>, lexeme: &mut String) {
-    buf.push(Token::new(lexeme, TokenType::Key));
-    buf.push(Token::no_lexeme(TokenType::Assigner));
+fn handle_right_bracket(buf: &mut Vec<Token>, lexeme: &mut String, line: usize, col: usize) {
+    if !lexeme.is_empty() {
+        buf.push(Token::new(lexeme, TokenType::Value).with_position(line, col));
+    }
+    buf.push(Token::no_lexeme(TokenType::RightBracket).with_position(line, col));
 
     lexeme.clear();
 }
 
-fn handle_separator(buf: &mut Vec<Token>, lexeme: &mut String) {
-    buf.push(Token::new(lexeme, TokenType::Value));
-    buf.push(Token::no_lexeme(TokenType::Separator));
+fn handle_assigner(buf: &mut Vec<Token>, lexeme: &mut String, line: usize, col: usize) {
+    buf.push(Token::new(lexeme, TokenType::Key).with_position(line, col));
+    buf.push(Token::no_lexeme(TokenType::Assigner).with_position(line, col));
 
     lexeme.clear();
 }
 
+fn handle_separator(buf: &mut Vec<Token>, lexeme: &mut String, line: usize, col: usize) {
+    if !lexeme.is_empty() {
+        buf.push(Token::new(lexeme, TokenType::Value).with_position(line, col));
+    }
+    buf.push(Token::no_lexeme(TokenType::Separator).with_position(line, col));
+
+    lexeme.clear();
+}
+
+/// Reads the four hex digits of a `\uXXXX` escape (the cursor must already
+/// be positioned right after the `u`) into a UTF-16 code unit.
+fn read_unicode_escape(chars: &mut Peekable<Chars>, col: &mut usize) -> Option<u32> {
+    let mut hex = String::with_capacity(4);
+
+    for _ in 0..4 {
+        let ch = chars.next()?;
+        *col += 1;
+        hex.push(ch);
+    }
+
+    u32::from_str_radix(&hex, 16).ok()
+}
+
+/// Consumes a quoted JSON string starting right after the opening `"`,
+/// decoding `\" \\ \/ \b \f \n \r \t` and `\uXXXX` (including surrogate
+/// pairs) into their real characters, and appends the result - still
+/// wrapped in quotes - to `lexeme`. Keeping the quotes lets a plain value
+/// lexeme (`true`/`42`/`"hi"`) still be told apart by `lexeme_to_val`.
+pub(crate) fn lex_string(chars: &mut Peekable<Chars>, lexeme: &mut String, line: &mut usize, col: &mut usize) {
+    lexeme.push('"');
+
+    while let Some(ch) = chars.next() {
+        *col += 1;
+        if ch == '\n' { *line += 1; *col = 0; }
+
+        match ch {
+            '"' => break,
+            '\\' => match chars.next() {
+                Some('"') => { *col += 1; lexeme.push('"'); }
+                Some('\\') => { *col += 1; lexeme.push('\\'); }
+                Some('/') => { *col += 1; lexeme.push('/'); }
+                Some('b') => { *col += 1; lexeme.push('\u{8}'); }
+                Some('f') => { *col += 1; lexeme.push('\u{c}'); }
+                Some('n') => { *col += 1; lexeme.push('\n'); }
+                Some('r') => { *col += 1; lexeme.push('\r'); }
+                Some('t') => { *col += 1; lexeme.push('\t'); }
+                Some('u') => {
+                    *col += 1;
+                    if let Some(high) = read_unicode_escape(chars, col) {
+                        let decoded = if (0xD800..=0xDBFF).contains(&high) {
+                            match (chars.next(), chars.next()) {
+                                (Some('\\'), Some('u')) => {
+                                    *col += 2;
+                                    read_unicode_escape(chars, col)
+                                        .filter(|low| (0xDC00..=0xDFFF).contains(low))
+                                        .and_then(|low| char::from_u32(
+                                            ((high - 0xD800) << 10) + (low - 0xDC00) + 0x10000))
+                                }
+                                _ => None,
+                            }
+                        } else {
+                            char::from_u32(high)
+                        };
+
+                        if let Some(decoded) = decoded {
+                            lexeme.push(decoded);
+                        }
+                    }
+                }
+                Some(other) => { *col += 1; lexeme.push(other); }
+                None => break,
+            },
+            other => lexeme.push(other),
+        }
+    }
+
+    lexeme.push('"');
+}
+
 pub fn lex(data: &str) -> Vec<Token> {
     let mut result: Vec<Token> = vec![];
     let mut lexeme = String::with_capacity(36);
+    let mut chars = data.chars().peekable();
+    let mut line = 1usize;
+    let mut col = 0usize;
+
+    while let Some(ch) = chars.next() {
+        col += 1;
 
-    for ch in data.chars() {
         match ch {
-            '{' => result.push(Token::no_lexeme(TokenType::OpeningBrace)),
-            '}' => handle_closing_brace(&mut result, &mut lexeme),
-            ':' => handle_assigner(&mut result, &mut lexeme),
-            ',' => handle_separator(&mut result, &mut lexeme),
-            '\n' | '\r' | '\t' | ' ' => (),
+            '{' => result.push(Token::no_lexeme(TokenType::OpeningBrace).with_position(line, col)),
+            '}' => handle_closing_brace(&mut result, &mut lexeme, line, col),
+            '[' => result.push(Token::no_lexeme(TokenType::LeftBracket).with_position(line, col)),
+            ']' => handle_right_bracket(&mut result, &mut lexeme, line, col),
+            ':' => handle_assigner(&mut result, &mut lexeme, line, col),
+            ',' => handle_separator(&mut result, &mut lexeme, line, col),
+            '"' => lex_string(&mut chars, &mut lexeme, &mut line, &mut col),
+            '\n' => { line += 1; col = 0; }
+            ' ' | '\r' | '\t' => (),
             _ => lexeme.push(ch),
         }
     }
@@ -1,91 +1,175 @@
-use crate::parser::{token::{Token, TokenType}, lexer::{lex, lex_from_file}};
-use std::{collections::HashMap, io};
+use crate::parser::{
+    error::{JsonError, JsonResult},
+    token::{Token, TokenType},
+    lexer::{lex, lex_from_file},
+};
+use std::{collections::BTreeMap, io};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Either<F, S> {
-    First(F),
-    Second(S),
-}
-
-enum JsonValue {
+/// The fully-materialized DOM produced by `Parser`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Object(BTreeMap<String, Value>),
+    Array(Vec<Value>),
     String(String),
-    Int(i32),
-    Float(f32),
+    Int(i64),
+    Float(f64),
     Bool(bool),
-}
-
-struct KeyValPair {
-    pair: HashMap<String, Either<JsonValue, KeyValPair>>,
-}
-
-impl KeyValPair {
-    pub fn new() -> Self {
-        KeyValPair {
-            pair: HashMap::new(),
-        }
-    }
-
-    #[inline]
-    pub fn insert_normal(&mut self, key: &str, val: JsonValue) {
-        self.pair.insert(key.to_string(), Either::First(val));
-    }
+    Null,
 }
 
 pub struct Parser {
     tokens: Vec<Token>,
-    result: KeyValPair,
+    cursor: usize,
 }
 
 impl Parser {
-    fn new(tokens: Vec<Token>, result: KeyValPair) -> Self {
-        let mut parser = Parser {
-            tokens,
-            result,
-        };
-        parser.parse();
-        parser
+    fn new(tokens: Vec<Token>) -> Self {
+        Parser { tokens, cursor: 0 }
     }
 
     #[inline]
-    pub fn from_file(path: &str) -> io::Result<Self> {
-        Ok(Self::new(lex_from_file(path)?, KeyValPair::new()))
+    pub fn from_file(path: &str) -> io::Result<JsonResult<Value>> {
+        Ok(Self::new(lex_from_file(path)?).parse())
     }
 
     #[inline]
-    pub fn from_string(data: &str) -> Self {
-        Self::new(lex(data), KeyValPair::new())
+    pub fn from_string(data: &str) -> JsonResult<Value> {
+        Self::new(lex(data)).parse()
     }
 
-    fn string_to_val(val: &str) -> JsonValue {
+    pub(crate) fn string_to_val(val: &str) -> Value {
         if val == "true" {
-            JsonValue::Bool(true)
+            Value::Bool(true)
         } else if val == "false" {
-            JsonValue::Bool(false)
-        } else if let Ok(parsed) = val.parse::<i32>() {
-            JsonValue::Int(parsed)
-        } else if let Ok(parsed) = val.parse::<f32>() {
-            JsonValue::Float(parsed)
+            Value::Bool(false)
+        } else if val == "null" {
+            Value::Null
+        } else if let Ok(parsed) = val.parse::<i64>() {
+            Value::Int(parsed)
+        } else if let Ok(parsed) = val.parse::<f64>() {
+            Value::Float(parsed)
+        } else if val.len() >= 2 && val.starts_with('"') && val.ends_with('"') {
+            Value::String(val[1..val.len() - 1].to_string())
         } else {
-            JsonValue::String(val.to_string())
+            Value::String(val.to_string())
         }
     }
 
-    fn handle_key(&mut self, i: usize) {
-        if self.tokens[i + 2].token_type() == TokenType::Value {
-            self.result.insert_normal(
-                self.tokens[i].lexeme().as_ref().unwrap(),
-                Self::string_to_val(self.tokens[i + 2].lexeme().as_ref().unwrap()));
-        } else if self.tokens[i + 2].token_type() == TokenType::OpeningBrace {
+    #[inline]
+    fn current(&self) -> Option<&Token> {
+        self.tokens.get(self.cursor)
+    }
+
+    #[inline]
+    fn advance(&mut self) -> Option<&Token> {
+        let token = self.tokens.get(self.cursor);
+        if token.is_some() { self.cursor += 1; }
+        token
+    }
+
+    /// The line/column an unterminated container should be blamed on - the
+    /// last token lexed, or `(0, 0)` if the document was empty.
+    fn eof_error(&self) -> JsonError {
+        let (line, col) = self.tokens.last().map(|t| (t.line(), t.col())).unwrap_or((0, 0));
+        JsonError::InvalidJson(line, col)
+    }
 
+    /// Dispatches on the current token to parse whatever value starts here,
+    /// recursing for nested objects/arrays. Errors instead of panicking if
+    /// the document ends before a value starts.
+    fn parse_value(&mut self) -> JsonResult<Value> {
+        match self.current().map(|t| t.token_type()) {
+            Some(TokenType::OpeningBrace) => self.parse_object(),
+            Some(TokenType::LeftBracket) => self.parse_array(),
+            Some(_) => Ok(Self::string_to_val(self.advance().unwrap().lexeme().as_ref().unwrap())),
+            None => Err(self.eof_error()),
         }
     }
 
-    fn parse(&mut self) {
-        for i in 0..self.tokens.len() {
-            match self.tokens[i].token_type() {
-                TokenType::Key => self.handle_key(i),
-                _ => todo!(),
+    /// Parses `Key Assigner value (Separator)?` pairs until `ClosingBrace`.
+    /// Errors instead of panicking if the object is never closed.
+    fn parse_object(&mut self) -> JsonResult<Value> {
+        self.advance(); // `{`
+        let mut object = BTreeMap::new();
+
+        while self.current().map(|t| t.token_type()) != Some(TokenType::ClosingBrace) {
+            let Some(key_token) = self.advance() else { return Err(self.eof_error()); };
+            let key_lexeme = key_token.lexeme().as_ref().unwrap().clone();
+            let key = key_lexeme[1..key_lexeme.len() - 1].to_string();
+
+            self.advance(); // `:`
+            object.insert(key, self.parse_value()?);
+
+            if self.current().map(|t| t.token_type()) == Some(TokenType::Separator) {
+                self.advance();
             }
         }
+
+        self.advance(); // `}`
+        Ok(Value::Object(object))
+    }
+
+    /// Parses `value (Separator)?` elements until `RightBracket`. Errors
+    /// instead of panicking if the array is never closed.
+    fn parse_array(&mut self) -> JsonResult<Value> {
+        self.advance(); // `[`
+        let mut array = vec![];
+
+        while self.current().map(|t| t.token_type()) != Some(TokenType::RightBracket) {
+            array.push(self.parse_value()?);
+
+            if self.current().map(|t| t.token_type()) == Some(TokenType::Separator) {
+                self.advance();
+            }
+        }
+
+        self.advance(); // `]`
+        Ok(Value::Array(array))
+    }
+
+    fn parse(mut self) -> JsonResult<Value> {
+        self.parse_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_handles_an_empty_object() {
+        assert_eq!(Parser::from_string("{}"), Ok(Value::Object(BTreeMap::new())));
+    }
+
+    #[test]
+    fn parse_handles_an_empty_array() {
+        assert_eq!(Parser::from_string("[]"), Ok(Value::Array(vec![])));
+    }
+
+    #[test]
+    fn parse_handles_an_empty_container_followed_by_a_sibling() {
+        let expected = Value::Object(BTreeMap::from([
+            ("a".to_string(), Value::Object(BTreeMap::new())),
+            ("b".to_string(), Value::Int(2)),
+        ]));
+        assert_eq!(Parser::from_string(r#"{"a":{},"b":2}"#), Ok(expected));
+    }
+
+    #[test]
+    fn parse_strips_quotes_off_string_values() {
+        let expected = Value::Object(BTreeMap::from([
+            ("k".to_string(), Value::String("hi".to_string())),
+        ]));
+        assert_eq!(Parser::from_string(r#"{"k":"hi"}"#), Ok(expected));
+    }
+
+    #[test]
+    fn parse_reports_an_error_instead_of_panicking_on_an_unterminated_object() {
+        assert!(Parser::from_string("{").is_err());
+    }
+
+    #[test]
+    fn parse_reports_an_error_instead_of_panicking_on_an_unterminated_array() {
+        assert!(Parser::from_string("[1,2").is_err());
     }
 }
@@ -0,0 +1,191 @@
+use crate::parser::{lexer::lex_string, parser::{Parser, Value}};
+use std::{collections::VecDeque, iter::Peekable, str::Chars};
+
+/// A single step of a `StreamingParser` pull, mirroring the event stream a
+/// caller would see if they drove the lexer by hand.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonEvent {
+    ObjectStart,
+    ObjectEnd,
+    ArrayStart,
+    ArrayEnd,
+    Key(String),
+    Value(Value),
+    /// Braces/brackets didn't balance; terminates the stream.
+    Error,
+}
+
+enum Container {
+    Object,
+    Array,
+}
+
+/// A pull-style parser that yields `JsonEvent`s one at a time instead of
+/// building a `Value` tree, driving the input character-by-character so a
+/// caller can bail out (or the input can keep arriving) without the whole
+/// document ever being tokenized up front.
+pub struct StreamingParser<'a> {
+    chars: Peekable<Chars<'a>>,
+    lexeme: String,
+    stack: Vec<Container>,
+    line: usize,
+    col: usize,
+    errored: bool,
+    /// Closing a container may flush a pending value before the
+    /// `ObjectEnd`/`ArrayEnd` itself; this holds the overflow event until
+    /// the next `next()` call.
+    pending: VecDeque<JsonEvent>,
+}
+
+impl<'a> StreamingParser<'a> {
+    pub fn from_string(data: &'a str) -> Self {
+        StreamingParser {
+            chars: data.chars().peekable(),
+            lexeme: String::with_capacity(36),
+            stack: vec![],
+            line: 1,
+            col: 0,
+            errored: false,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Flushes the in-progress lexeme as a `Value` event, if one was
+    /// accumulated since the last structural character.
+    fn flush_value(&mut self) {
+        if !self.lexeme.is_empty() {
+            self.pending.push_back(JsonEvent::Value(Parser::string_to_val(&self.lexeme)));
+            self.lexeme.clear();
+        }
+    }
+
+    /// Flushes the in-progress lexeme as a `Key` event, stripping its
+    /// surrounding quotes.
+    fn flush_key(&mut self) {
+        let key = if self.lexeme.len() >= 2 {
+            self.lexeme[1..self.lexeme.len() - 1].to_string()
+        } else {
+            self.lexeme.clone()
+        };
+        self.pending.push_back(JsonEvent::Key(key));
+        self.lexeme.clear();
+    }
+
+    /// Queues `event` behind anything already pending and returns whichever
+    /// event is oldest, so a flushed value is always yielded before the
+    /// structural event that triggered the flush.
+    fn emit(&mut self, event: JsonEvent) -> Option<JsonEvent> {
+        self.pending.push_back(event);
+        self.pending.pop_front()
+    }
+}
+
+impl<'a> Iterator for StreamingParser<'a> {
+    type Item = JsonEvent;
+
+    fn next(&mut self) -> Option<JsonEvent> {
+        if let Some(event) = self.pending.pop_front() { return Some(event); }
+        if self.errored { return None; }
+
+        while let Some(ch) = self.chars.next() {
+            self.col += 1;
+
+            match ch {
+                '{' => {
+                    self.stack.push(Container::Object);
+                    return Some(JsonEvent::ObjectStart);
+                }
+                '}' => {
+                    self.flush_value();
+                    let event = match self.stack.pop() {
+                        Some(Container::Object) => JsonEvent::ObjectEnd,
+                        _ => { self.errored = true; JsonEvent::Error }
+                    };
+                    return self.emit(event);
+                }
+                '[' => {
+                    self.stack.push(Container::Array);
+                    return Some(JsonEvent::ArrayStart);
+                }
+                ']' => {
+                    self.flush_value();
+                    let event = match self.stack.pop() {
+                        Some(Container::Array) => JsonEvent::ArrayEnd,
+                        _ => { self.errored = true; JsonEvent::Error }
+                    };
+                    return self.emit(event);
+                }
+                ':' => {
+                    self.flush_key();
+                    return self.pending.pop_front();
+                }
+                ',' => {
+                    self.flush_value();
+                    if let Some(event) = self.pending.pop_front() {
+                        return Some(event);
+                    }
+                }
+                '"' => lex_string(&mut self.chars, &mut self.lexeme, &mut self.line, &mut self.col),
+                '\n' => { self.line += 1; self.col = 0; }
+                ' ' | '\r' | '\t' => (),
+                other => self.lexeme.push(other),
+            }
+        }
+
+        self.flush_value();
+        if !self.stack.is_empty() {
+            self.errored = true;
+            return self.emit(JsonEvent::Error);
+        }
+
+        self.pending.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn walks_a_nested_object() {
+        let mut events = StreamingParser::from_string(r#"{"a":[1,2]}"#);
+        assert_eq!(events.next(), Some(JsonEvent::ObjectStart));
+        assert_eq!(events.next(), Some(JsonEvent::Key("a".to_string())));
+        assert_eq!(events.next(), Some(JsonEvent::ArrayStart));
+        assert_eq!(events.next(), Some(JsonEvent::Value(Value::Int(1))));
+        assert_eq!(events.next(), Some(JsonEvent::Value(Value::Int(2))));
+        assert_eq!(events.next(), Some(JsonEvent::ArrayEnd));
+        assert_eq!(events.next(), Some(JsonEvent::ObjectEnd));
+        assert_eq!(events.next(), None);
+    }
+
+    #[test]
+    fn handles_an_empty_object() {
+        let mut events = StreamingParser::from_string("{}");
+        assert_eq!(events.next(), Some(JsonEvent::ObjectStart));
+        assert_eq!(events.next(), Some(JsonEvent::ObjectEnd));
+        assert_eq!(events.next(), None);
+    }
+
+    #[test]
+    fn reports_unbalanced_brackets_as_an_error() {
+        let mut events = StreamingParser::from_string("}");
+        assert_eq!(events.next(), Some(JsonEvent::Error));
+        assert_eq!(events.next(), None);
+    }
+
+    #[test]
+    fn reports_an_unterminated_object_as_an_error_at_eof() {
+        let events: Vec<_> = StreamingParser::from_string("{").collect();
+        assert_eq!(events, vec![JsonEvent::ObjectStart, JsonEvent::Error]);
+    }
+
+    #[test]
+    fn reports_an_unterminated_array_as_an_error_at_eof() {
+        let events: Vec<_> = StreamingParser::from_string("[1,2").collect();
+        assert_eq!(events, vec![
+            JsonEvent::ArrayStart, JsonEvent::Value(Value::Int(1)),
+            JsonEvent::Value(Value::Int(2)), JsonEvent::Error,
+        ]);
+    }
+}
@@ -0,0 +1,70 @@
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum TokenType {
+    /// `{`
+    OpeningBrace,
+    /// `}`
+    ClosingBrace,
+    /// `[`
+    LeftBracket,
+    /// `]`
+    RightBracket,
+    /// `key`: value
+    Key,
+    /// key`:` value
+    Assigner,
+    /// key: `value`
+    Value,
+    /// key: value`,`
+    Separator,
+}
+
+pub struct Token {
+    lexeme: Option<String>,
+    token_type: TokenType,
+    line: usize,
+    col: usize,
+}
+
+impl Token {
+    pub fn new(lexeme: &str, token_type: TokenType) -> Self {
+        Token {
+            lexeme: Some(lexeme.to_string()),
+            token_type,
+            line: 0,
+            col: 0,
+        }
+    }
+
+    pub fn no_lexeme(token_type: TokenType) -> Self {
+        Token {
+            lexeme: None,
+            token_type,
+            line: 0,
+            col: 0,
+        }
+    }
+
+    /// Stamps this token with the line/column it was lexed from.
+    pub fn with_position(mut self, line: usize, col: usize) -> Self {
+        self.line = line;
+        self.col = col;
+        self
+    }
+
+    pub fn lexeme(&self) -> &Option<String> {
+        &self.lexeme
+    }
+
+    pub fn token_type(&self) -> TokenType {
+        self.token_type
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn col(&self) -> usize {
+        self.col
+    }
+}
@@ -5,6 +5,10 @@ pub enum TokenType {
     OpeningBrace,
     /// `}`
     ClosingBrace,
+    /// `[`
+    LeftBracket,
+    /// `]`
+    RightBracket,
     /// `key`: value
     Key,
     /// key`:` value
@@ -19,13 +23,17 @@ pub enum TokenType {
 pub struct Token {
     lexeme: Option<String>,
     token_type: TokenType,
+    line: usize,
+    col: usize,
 }
 
 impl Token {
     pub fn new(lexeme: &str, token_type: TokenType) -> Self {
         Token {
             lexeme: Some(lexeme.to_string()),
-            token_type
+            token_type,
+            line: 0,
+            col: 0,
         }
     }
 
@@ -33,14 +41,33 @@ impl Token {
         Token {
             lexeme: None,
             token_type,
+            line: 0,
+            col: 0,
         }
-    } 
+    }
+
+    /// Stamps this token with the line/column it was lexed from. Tokens
+    /// synthesized outside of lexing (e.g. by `JsonBuilder`) keep the
+    /// default `(0, 0)` position.
+    pub fn with_position(mut self, line: usize, col: usize) -> Self {
+        self.line = line;
+        self.col = col;
+        self
+    }
 
     pub fn lexeme(&self) -> &Option<String> {
         &self.lexeme
     }
-    
+
     pub fn token_type(&self) -> TokenType {
         self.token_type
     }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn col(&self) -> usize {
+        self.col
+    }
 }
@@ -0,0 +1,45 @@
+use feather_json::json::{Json, JsonValue};
+use feather_json::FeatherJson;
+
+#[derive(FeatherJson, Debug, PartialEq)]
+struct Address {
+    city: String,
+}
+
+#[derive(FeatherJson, Debug, PartialEq)]
+struct Account {
+    id: i64,
+    tags: Vec<String>,
+    address: Address,
+}
+
+fn sample_account() -> Account {
+    Account {
+        id: 1,
+        tags: vec!["admin".to_string(), "beta".to_string()],
+        address: Address { city: "Reno".to_string() },
+    }
+}
+
+#[test]
+fn encode_then_decode_round_trips_a_struct_with_only_required_fields() {
+    let account = sample_account();
+    let json = Json::encode(&account);
+    let decoded: Account = json.decode().unwrap();
+
+    assert_eq!(decoded, account);
+}
+
+#[test]
+fn encode_writes_a_vec_field_as_a_json_array() {
+    let json = Json::encode(&sample_account());
+    assert_eq!(json.get(&["tags"]), Ok(JsonValue::Array(vec![
+        JsonValue::String("admin".to_string()), JsonValue::String("beta".to_string()),
+    ])));
+}
+
+#[test]
+fn encode_writes_a_nested_derived_struct_as_a_json_object() {
+    let json = Json::encode(&sample_account());
+    assert_eq!(json.get(&["address", "city"]), Ok(JsonValue::String("Reno".to_string())));
+}
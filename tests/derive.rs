@@ -0,0 +1,33 @@
+use feather_json::json::Json;
+use feather_json::FeatherJson;
+
+#[derive(FeatherJson)]
+struct Profile {
+    name: String,
+    nickname: Option<String>,
+}
+
+#[test]
+fn absent_optional_field_decodes_to_none() {
+    let json = Json::from_string(r#"{"name": "Ada"}"#);
+    let profile: Profile = json.decode().unwrap();
+
+    assert_eq!(profile.name, "Ada");
+    assert_eq!(profile.nickname, None);
+}
+
+#[test]
+fn present_optional_field_decodes_to_some() {
+    let json = Json::from_string(r#"{"name": "Ada", "nickname": "Countess"}"#);
+    let profile: Profile = json.decode().unwrap();
+
+    assert_eq!(profile.nickname, Some("Countess".to_string()));
+}
+
+#[test]
+fn missing_required_field_still_errors() {
+    let json = Json::from_string(r#"{"nickname": "Countess"}"#);
+    let result: Result<Profile, _> = json.decode();
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,50 @@
+use feather_json::json::{Json, JsonValue};
+
+#[test]
+fn to_value_materializes_a_fully_recursive_tree() {
+    let json = Json::from_string(r#"{"a":1,"b":{"c":[2,3]}}"#);
+
+    let expected = JsonValue::Object(vec![
+        ("a".to_string(), JsonValue::Int(1)),
+        ("b".to_string(), JsonValue::Object(vec![
+            ("c".to_string(), JsonValue::Array(vec![JsonValue::Int(2), JsonValue::Int(3)])),
+        ])),
+    ]);
+
+    assert_eq!(json.to_value(), Ok(expected));
+}
+
+#[test]
+fn get_resolves_a_nested_path() {
+    let json = Json::from_string(r#"{"a":{"b":[1,2,3]}}"#);
+    assert_eq!(json.get(&["a", "b"]), Ok(JsonValue::Array(vec![
+        JsonValue::Int(1), JsonValue::Int(2), JsonValue::Int(3),
+    ])));
+}
+
+#[test]
+fn to_value_widens_null_and_floats_nested_inside_an_array() {
+    let json = Json::from_string(r#"{"a":[null,3.5,-7]}"#);
+
+    let expected = JsonValue::Object(vec![
+        ("a".to_string(), JsonValue::Array(vec![
+            JsonValue::Null, JsonValue::Float(3.5), JsonValue::Int(-7),
+        ])),
+    ]);
+
+    assert_eq!(json.to_value(), Ok(expected));
+}
+
+#[test]
+fn get_decodes_escape_sequences_in_string_values() {
+    let json = Json::from_string(r#"{"key": "a\"b\nc"}"#);
+    assert_eq!(json.get(&["key"]), Ok(JsonValue::String("a\"b\nc".to_string())));
+}
+
+#[test]
+fn string_escaping_round_trips_through_encode_and_decode() {
+    let original = JsonValue::String("a\"b\nc\\d".to_string());
+    let encoded = feather_json::encoder::to_string(&original);
+    let decoded = Json::from_string(&format!(r#"{{"key":{encoded}}}"#)).get(&["key"]);
+    assert_eq!(decoded, Ok(original));
+}
@@ -0,0 +1,13 @@
+use feather_json::json::{Json, JsonValue};
+
+#[test]
+fn recursive_descent_combined_with_a_child_selector_dedupes_and_preserves_order() {
+    let json = Json::from_string(r#"{"x":5,"wrapper":{"book":"B1","y":9}}"#);
+    assert_eq!(json.query("$..y").unwrap(), vec![JsonValue::Int(9)]);
+}
+
+#[test]
+fn recursive_descent_visits_matches_in_document_order() {
+    let json = Json::from_string(r#"{"a":{"z":1},"b":{"z":2}}"#);
+    assert_eq!(json.query("$..z").unwrap(), vec![JsonValue::Int(1), JsonValue::Int(2)]);
+}